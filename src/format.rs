@@ -0,0 +1,92 @@
+//! Human-readable formatting of durations and relative times.
+//!
+//! Durations are unsigned `std::time::Duration`s, so callers pick the
+//! direction themselves by calling [`until`] or [`since`].
+
+use std::time::Duration;
+
+const UNITS: [(u64, &str); 4] = [(86_400, "d"), (3_600, "h"), (60, "m"), (1, "s")];
+
+/// Formats a duration using up to two adjacent non-zero units, stopping at
+/// the first zero unit, e.g. `2 h 15 m`, or `1 d` for `1 d 0 h 5 m`.
+///
+/// Remaining smaller units are truncated rather than rounded, so a duration
+/// is never reported as longer than it is. Durations under one second
+/// format as `0 s`.
+pub fn humanize(duration: Duration) -> String {
+    let mut remaining = duration.as_secs();
+    let mut parts = Vec::with_capacity(2);
+    for (size, unit) in UNITS {
+        if parts.len() == 2 {
+            break;
+        }
+        let count = remaining / size;
+        remaining %= size;
+        if count > 0 {
+            parts.push(format!("{count} {unit}"));
+        } else if !parts.is_empty() {
+            break;
+        }
+    }
+    if parts.is_empty() {
+        return "0 s".to_string();
+    }
+    parts.join(" ")
+}
+
+/// Formats a duration until a future instant, e.g. `in 2 h 15 m`.
+pub fn until(duration: Duration) -> String {
+    if duration.as_secs() == 0 {
+        return "now".to_string();
+    }
+    format!("in {}", humanize(duration))
+}
+
+/// Formats a duration since a past instant, e.g. `45 m ago`.
+///
+/// Any leading verb such as "started" is left to the caller.
+pub fn since(duration: Duration) -> String {
+    if duration.as_secs() == 0 {
+        return "now".to_string();
+    }
+    format!("{} ago", humanize(duration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_sub_second_is_zero() {
+        assert_eq!(humanize(Duration::ZERO), "0 s");
+        assert_eq!(humanize(Duration::from_millis(999)), "0 s");
+    }
+
+    #[test]
+    fn humanize_two_units() {
+        assert_eq!(humanize(Duration::from_secs(8100)), "2 h 15 m");
+    }
+
+    #[test]
+    fn humanize_truncates_third_unit() {
+        assert_eq!(humanize(Duration::from_secs(90061)), "1 d 1 h");
+    }
+
+    #[test]
+    fn humanize_stops_at_zero_unit() {
+        assert_eq!(humanize(Duration::from_secs(86700)), "1 d");
+    }
+
+    #[test]
+    fn humanize_max_does_not_panic() {
+        humanize(Duration::MAX);
+    }
+
+    #[test]
+    fn relative_formats() {
+        assert_eq!(until(Duration::ZERO), "now");
+        assert_eq!(since(Duration::ZERO), "now");
+        assert_eq!(until(Duration::from_secs(8100)), "in 2 h 15 m");
+        assert_eq!(since(Duration::from_secs(2700)), "45 m ago");
+    }
+}